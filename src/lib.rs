@@ -129,18 +129,134 @@
 
 extern crate chrono;
 extern crate log;
+#[cfg(feature = "regex")]
+extern crate regex;
 extern crate termcolor;
 extern crate thread_local;
 
-use chrono::Local;
+use chrono::{Local, Utc};
 use log::{Level, LevelFilter, Log, Metadata, Record};
+#[cfg(feature = "regex")]
+use regex::Regex;
 use std::cell::RefCell;
+use std::env;
 use std::fmt;
+use std::fs;
 use std::io::{self, Write};
-use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 pub use termcolor::ColorChoice;
+use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
 use thread_local::CachedThreadLocal;
 
+/// The signature of a custom line-formatting callback, as passed to
+/// [`StdErrLog::format`](struct.StdErrLog.html#method.format).
+type FormatFn = dyn Fn(&mut dyn Write, &Record) -> io::Result<()> + Sync + Send;
+
+/// Where a `StdErrLog` should send its output.
+#[derive(Default)]
+pub enum Target {
+    /// Write colored output to stderr (the default).
+    #[default]
+    Stderr,
+    /// Write colored output to stdout.
+    Stdout,
+    /// Write uncolored output to an arbitrary sink, such as a file or an
+    /// in-memory buffer used by tests.
+    Pipe(Box<dyn Write + Send>),
+}
+
+impl fmt::Debug for Target {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Target::Stderr => "Stderr",
+            Target::Stdout => "Stdout",
+            Target::Pipe(_) => "Pipe(..)",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The resolved, clonable form of a `Target` actually stored on `StdErrLog`.
+/// `Std` reuses the per-thread colored `StandardStream` writer; `Pipe` is
+/// shared across threads behind a `Mutex` and is never colored.
+#[derive(Clone)]
+enum StoredTarget {
+    Std(bool),
+    Pipe(Arc<Mutex<io::LineWriter<Box<dyn Write + Send>>>>),
+}
+
+impl fmt::Debug for StoredTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            StoredTarget::Std(true) => "Stdout",
+            StoredTarget::Std(false) => "Stderr",
+            StoredTarget::Pipe(_) => "Pipe(..)",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A `Write` sink that writes into `path`, renaming it to `path` + `.1` and
+/// starting a fresh file on the first write after it has reached
+/// `max_bytes`. Used as the
+/// `Target::Pipe` backing a [`log_file`](struct.StdErrLog.html#method.log_file)
+/// logger; the `Target::Pipe` machinery already serializes all access to
+/// this writer behind a single `Mutex`, so rotation is safe across threads
+/// without any extra locking here.
+struct RotatingFileWriter {
+    path: PathBuf,
+    max_bytes: u64,
+    file: fs::File,
+    written: u64,
+}
+
+impl RotatingFileWriter {
+    fn new<P: AsRef<Path>>(path: P, max_bytes: u64) -> io::Result<RotatingFileWriter> {
+        let path = path.as_ref().to_path_buf();
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(RotatingFileWriter {
+            path,
+            max_bytes,
+            file,
+            written,
+        })
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        fs::rename(&self.path, &rotated)?;
+        self.file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written >= self.max_bytes {
+            self.rotate()?;
+        }
+        let n = self.file.write(buf)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
 /// State of the timestampping in the logger.
 #[derive(Clone, Copy, Debug)]
 pub enum Timestamp {
@@ -152,6 +268,10 @@ pub enum Timestamp {
     Microsecond,
     /// Timestamp with nanosecond granularity
     Nanosecond,
+    /// RFC 3339 timestamp in UTC rather than local time
+    Rfc3339Utc,
+    /// Seconds elapsed since the logger was `init`'d, rendered like `[   12.004s]`
+    Uptime,
 }
 
 /// Data specific to this logger
@@ -160,20 +280,31 @@ pub struct StdErrLog {
     quiet: bool,
     timestamp: Timestamp,
     modules: Vec<String>,
+    directives: Vec<(Option<String>, LevelFilter)>,
+    format: Option<Arc<FormatFn>>,
+    target: StoredTarget,
+    #[cfg(feature = "regex")]
+    message_filter: Option<Regex>,
+    start: Mutex<Instant>,
     writer: CachedThreadLocal<RefCell<io::LineWriter<StandardStream>>>,
     color_choice: ColorChoice,
 }
 
 impl fmt::Debug for StdErrLog {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("StdErrLog")
+        let mut debug_struct = f.debug_struct("StdErrLog");
+        debug_struct
             .field("verbosity", &self.verbosity)
             .field("quiet", &self.quiet)
             .field("timestamp", &self.timestamp)
             .field("modules", &self.modules)
-            .field("writer", &"stderr")
-            .field("color_choice", &self.color_choice)
-            .finish()
+            .field("directives", &self.directives)
+            .field("format", &self.format.is_some())
+            .field("target", &self.target)
+            .field("color_choice", &self.color_choice);
+        #[cfg(feature = "regex")]
+        debug_struct.field("message_filter", &self.message_filter);
+        debug_struct.finish()
     }
 }
 
@@ -181,6 +312,12 @@ impl Clone for StdErrLog {
     fn clone(&self) -> StdErrLog {
         StdErrLog {
             modules: self.modules.clone(),
+            directives: self.directives.clone(),
+            format: self.format.clone(),
+            target: self.target.clone(),
+            #[cfg(feature = "regex")]
+            message_filter: self.message_filter.clone(),
+            start: Mutex::new(*self.start.lock().unwrap()),
             writer: CachedThreadLocal::new(),
             ..*self
         }
@@ -189,7 +326,10 @@ impl Clone for StdErrLog {
 
 impl Log for StdErrLog {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= self.log_level_filter()
+        if self.quiet {
+            return false;
+        }
+        metadata.level() <= self.level_for(metadata.target())
     }
 
     fn log(&self, record: &Record) {
@@ -205,56 +345,81 @@ impl Log for StdErrLog {
             Some(module) => self.includes_module(module),
             None => true,
         };
-        if should_log {
-            let writer = self.writer.get_or(|| {
-                Box::new(RefCell::new(io::LineWriter::new(
-                    StandardStream::stderr(self.color_choice),
-                )))
-            });
-            let mut writer = writer.borrow_mut();
-            let color = match record.metadata().level() {
-                Level::Error => Color::Red,
-                Level::Warn => Color::Magenta,
-                Level::Info => Color::Yellow,
-                Level::Debug => Color::Cyan,
-                Level::Trace => Color::Blue,
+        #[cfg(feature = "regex")]
+        let should_log = should_log
+            && match self.message_filter {
+                Some(ref pattern) => pattern.is_match(&format!("{}", record.args())),
+                None => true,
             };
-            {
-                writer
-                    .get_mut()
-                    .set_color(ColorSpec::new().set_fg(Some(color)))
-                    .expect("failed to set color");
-            }
-            match self.timestamp {
-                Timestamp::Second => {
-                    let fmt = "%Y-%m-%dT%H:%M:%S%:z";
-                    let _ = write!(writer, "{} - ", Local::now().format(fmt));
-                }
-                Timestamp::Microsecond => {
-                    let fmt = "%Y-%m-%dT%H:%M:%S%.6f%:z";
-                    let _ = write!(writer, "{} - ", Local::now().format(fmt));
+        if should_log {
+            match self.target {
+                StoredTarget::Std(stdout) => {
+                    let writer = self.writer.get_or(|| {
+                        Box::new(RefCell::new(io::LineWriter::new(if stdout {
+                            StandardStream::stdout(self.color_choice)
+                        } else {
+                            StandardStream::stderr(self.color_choice)
+                        })))
+                    });
+                    let mut writer = writer.borrow_mut();
+
+                    if let Some(ref format) = self.format {
+                        let _ = format(&mut *writer, record);
+                        return;
+                    }
+
+                    let color = Self::level_color(record.metadata().level());
+                    {
+                        writer
+                            .get_mut()
+                            .set_color(ColorSpec::new().set_fg(Some(color)))
+                            .expect("failed to set color");
+                    }
+                    if let Some(timestamp) =
+                        Self::format_timestamp(self.timestamp, *self.start.lock().unwrap())
+                    {
+                        let _ = write!(writer, "{} - ", timestamp);
+                    }
+                    let _ = writeln!(writer, "{} - {}", record.level(), record.args());
+                    {
+                        writer.get_mut().reset().expect("failed to reset the color");
+                    }
                 }
-                Timestamp::Nanosecond => {
-                    let fmt = "%Y-%m-%dT%H:%M:%S%.9f%:z";
-                    let _ = write!(writer, "{} - ", Local::now().format(fmt));
+                StoredTarget::Pipe(ref sink) => {
+                    let mut writer = sink.lock().expect("pipe sink lock poisoned");
+
+                    if let Some(ref format) = self.format {
+                        let _ = format(&mut *writer, record);
+                        return;
+                    }
+
+                    if let Some(timestamp) =
+                        Self::format_timestamp(self.timestamp, *self.start.lock().unwrap())
+                    {
+                        let _ = write!(writer, "{} - ", timestamp);
+                    }
+                    let _ = writeln!(writer, "{} - {}", record.level(), record.args());
                 }
-                Timestamp::Off => {}
-            }
-            let _ = writeln!(writer, "{} - {}", record.level(), record.args());
-            {
-                writer.get_mut().reset().expect("failed to reset the color");
             }
         }
     }
 
     fn flush(&self) {
-        let writer = self.writer.get_or(|| {
-            Box::new(RefCell::new(io::LineWriter::new(
-                StandardStream::stderr(self.color_choice),
-            )))
-        });
-        let mut writer = writer.borrow_mut();
-        writer.flush().ok();
+        match self.target {
+            StoredTarget::Std(stdout) => {
+                let writer = self.writer.get_or(|| {
+                    Box::new(RefCell::new(io::LineWriter::new(if stdout {
+                        StandardStream::stdout(self.color_choice)
+                    } else {
+                        StandardStream::stderr(self.color_choice)
+                    })))
+                });
+                writer.borrow_mut().flush().ok();
+            }
+            StoredTarget::Pipe(ref sink) => {
+                sink.lock().expect("pipe sink lock poisoned").flush().ok();
+            }
+        }
     }
 }
 
@@ -266,6 +431,12 @@ impl StdErrLog {
             quiet: false,
             timestamp: Timestamp::Off,
             modules: Vec::new(),
+            directives: Vec::new(),
+            format: None,
+            target: StoredTarget::Std(false),
+            #[cfg(feature = "regex")]
+            message_filter: None,
+            start: Mutex::new(Instant::now()),
             writer: CachedThreadLocal::new(),
             color_choice: ColorChoice::Auto,
         }
@@ -303,6 +474,88 @@ impl StdErrLog {
         self
     }
 
+    /// Sets where log lines are written, defaulting to stderr
+    pub fn target(&mut self, target: Target) -> &mut StdErrLog {
+        self.target = match target {
+            Target::Stderr => StoredTarget::Std(false),
+            Target::Stdout => StoredTarget::Std(true),
+            Target::Pipe(pipe) => {
+                StoredTarget::Pipe(Arc::new(Mutex::new(io::LineWriter::new(pipe))))
+            }
+        };
+        self
+    }
+
+    /// Redirects log output to a size-capped, rotating file at `path`,
+    /// replacing the current target (nothing is written to stderr or stdout)
+    pub fn log_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        max_bytes: u64,
+    ) -> io::Result<&mut StdErrLog> {
+        let writer = RotatingFileWriter::new(path, max_bytes)?;
+        Ok(self.target(Target::Pipe(Box::new(writer))))
+    }
+
+    /// Only emits records whose formatted message matches `pattern`
+    #[cfg(feature = "regex")]
+    pub fn message_filter(&mut self, pattern: Regex) -> &mut StdErrLog {
+        self.message_filter = Some(pattern);
+        self
+    }
+
+    /// Overrides the layout of each log line with a custom callback
+    pub fn format<F>(&mut self, format: F) -> &mut StdErrLog
+    where
+        F: Fn(&mut dyn Write, &Record) -> io::Result<()> + Sync + Send + 'static,
+    {
+        self.format = Some(Arc::new(format));
+        self
+    }
+
+    /// The color this logger uses by default for `level`.
+    pub fn level_color(level: Level) -> Color {
+        match level {
+            Level::Error => Color::Red,
+            Level::Warn => Color::Magenta,
+            Level::Info => Color::Yellow,
+            Level::Debug => Color::Cyan,
+            Level::Trace => Color::Blue,
+        }
+    }
+
+    /// Renders the current time for the given timestamp granularity, or `None` if disabled;
+    /// `Timestamp::Uptime` renders the time elapsed since `start` instead
+    pub fn format_timestamp(timestamp: Timestamp, start: Instant) -> Option<String> {
+        match timestamp {
+            Timestamp::Second => Some(format!("{}", Local::now().format("%Y-%m-%dT%H:%M:%S%:z"))),
+            Timestamp::Microsecond => Some(format!(
+                "{}",
+                Local::now().format("%Y-%m-%dT%H:%M:%S%.6f%:z")
+            )),
+            Timestamp::Nanosecond => Some(format!(
+                "{}",
+                Local::now().format("%Y-%m-%dT%H:%M:%S%.9f%:z")
+            )),
+            Timestamp::Rfc3339Utc => Some(format!("{}", Utc::now().format("%Y-%m-%dT%H:%M:%S%:z"))),
+            Timestamp::Uptime => {
+                let elapsed = start.elapsed();
+                Some(format!(
+                    "[{:>5}.{:03}s]",
+                    elapsed.as_secs(),
+                    elapsed.subsec_millis()
+                ))
+            }
+            Timestamp::Off => None,
+        }
+    }
+
+    /// The instant this logger was last `init`'d (or created, if it never was),
+    /// used as the zero point for `Timestamp::Uptime`
+    pub fn start_time(&self) -> Instant {
+        *self.start.lock().unwrap()
+    }
+
     /// specify a module to allow to log to stderr
     pub fn module<T: Into<String>>(&mut self, module: T) -> &mut StdErrLog {
         let to_insert = module.into();
@@ -324,11 +577,83 @@ impl StdErrLog {
         self
     }
 
+    /// Sets per-module log level directives from an `env_logger`/`RUST_LOG`-style comma-separated string
+    pub fn parse_filters<T: AsRef<str>>(&mut self, filters: T) -> &mut StdErrLog {
+        let filters = filters.as_ref();
+        #[cfg(feature = "regex")]
+        let (filters, pattern) = match filters.find('/') {
+            Some(i) => (&filters[..i], Some(&filters[i + 1..])),
+            None => (filters, None),
+        };
+
+        for directive in filters.split(',') {
+            let directive = directive.trim();
+            if directive.is_empty() {
+                continue;
+            }
+
+            match directive.find('=') {
+                None => match parse_level(directive) {
+                    Some(level) => self.verbosity = level,
+                    None => self
+                        .directives
+                        .push((Some(directive.to_string()), LevelFilter::Trace)),
+                },
+                Some(i) => {
+                    let module = &directive[..i];
+                    let level = &directive[i + 1..];
+                    let level = parse_level(level).unwrap_or(LevelFilter::Trace);
+                    self.directives.push((Some(module.to_string()), level));
+                }
+            }
+        }
+
+        #[cfg(feature = "regex")]
+        {
+            if let Some(pattern) = pattern {
+                if let Ok(pattern) = Regex::new(pattern) {
+                    self.message_filter(pattern);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Reads a parse_filters-style directive list from the named environment variable, if it is set
+    pub fn init_from_env<T: AsRef<str>>(&mut self, env: T) -> &mut StdErrLog {
+        if let Ok(filters) = env::var(env.as_ref()) {
+            self.parse_filters(filters);
+        }
+        self
+    }
+
+    /// Returns the most specific level filter that applies to `target`,
+    /// falling back to the global `verbosity` when no directive matches.
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.directives
+            .iter()
+            .filter_map(|&(ref module, level)| {
+                let module = module.as_ref()?;
+                if target == module.as_str() || target.starts_with(&format!("{}::", module)) {
+                    Some((module.len(), level))
+                } else {
+                    None
+                }
+            })
+            .max_by_key(|&(len, _)| len)
+            .map(|(_, level)| level)
+            .unwrap_or(self.verbosity)
+    }
+
     fn log_level_filter(&self) -> LevelFilter {
         if self.quiet {
             LevelFilter::Off
         } else {
-            self.verbosity
+            self.directives
+                .iter()
+                .map(|&(_, level)| level)
+                .fold(self.verbosity, ::std::cmp::max)
         }
     }
 
@@ -340,7 +665,8 @@ impl StdErrLog {
         // if a prefix of module_path is in `self.modules`, it must
         // be located at the first location before
         // where module_path would be.
-        match self.modules
+        match self
+            .modules
             .binary_search_by(|module| module.as_str().cmp(&module_path))
         {
             Ok(_) => {
@@ -357,6 +683,7 @@ impl StdErrLog {
 
     /// sets the the logger as active
     pub fn init(&self) -> Result<(), log::SetLoggerError> {
+        *self.start.lock().unwrap() = Instant::now();
         log::set_max_level(self.log_level_filter());
         log::set_boxed_logger(Box::new(self.clone()))
     }
@@ -373,8 +700,39 @@ pub fn new() -> StdErrLog {
     StdErrLog::new()
 }
 
+/// Parses a level name the same way `RUST_LOG`-style directives do,
+/// returning `None` if `s` isn't one of `error`, `warn`, `info`, `debug`,
+/// `trace` or `off` (case-insensitive).
+fn parse_level(s: &str) -> Option<LevelFilter> {
+    match s.to_lowercase().as_str() {
+        "off" => Some(LevelFilter::Off),
+        "error" => Some(LevelFilter::Error),
+        "warn" => Some(LevelFilter::Warn),
+        "info" => Some(LevelFilter::Info),
+        "debug" => Some(LevelFilter::Debug),
+        "trace" => Some(LevelFilter::Trace),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::LevelFilter;
+    use std::io::{self, Write};
+    use std::sync::{Arc, Mutex};
+
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
     #[test]
     fn test_default_level() {
         extern crate log;
@@ -383,4 +741,157 @@ mod tests {
 
         assert_eq!(log::Level::Error, log::max_level())
     }
+
+    #[test]
+    fn test_parse_filters_global_level() {
+        let mut log = super::new();
+        log.parse_filters("debug");
+        assert_eq!(LevelFilter::Debug, log.level_for("anything"));
+    }
+
+    #[test]
+    fn test_format_sets_custom_callback() {
+        extern crate log;
+
+        let buf = Arc::new(Mutex::new(Vec::new()));
+        let mut logger = super::new();
+        logger
+            .format(|buf, record| writeln!(buf, "CUSTOM {}", record.args()))
+            .target(super::Target::Pipe(Box::new(SharedBuf(buf.clone()))));
+
+        let record = log::Record::builder()
+            .args(format_args!("hello"))
+            .level(log::Level::Error)
+            .module_path(Some(module_path!()))
+            .build();
+        log::Log::log(&logger, &record);
+
+        let output = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert_eq!("CUSTOM hello\n", output);
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_message_filter_drops_non_matching_records() {
+        extern crate log;
+
+        let buf = Arc::new(Mutex::new(Vec::new()));
+        let mut logger = super::new();
+        logger
+            .timestamp(super::Timestamp::Off)
+            .message_filter(super::Regex::new("wanted").unwrap())
+            .target(super::Target::Pipe(Box::new(SharedBuf(buf.clone()))));
+
+        for message in &["wanted message", "unrelated message"] {
+            let args = format_args!("{}", message);
+            let record = log::Record::builder()
+                .args(args)
+                .level(log::Level::Error)
+                .module_path(Some(module_path!()))
+                .build();
+            log::Log::log(&logger, &record);
+        }
+
+        let output = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("wanted message"));
+        assert!(!output.contains("unrelated message"));
+    }
+
+    #[test]
+    fn test_format_timestamp_uptime() {
+        let start = std::time::Instant::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let rendered = super::StdErrLog::format_timestamp(super::Timestamp::Uptime, start).unwrap();
+        assert!(rendered.starts_with('['));
+        assert!(rendered.ends_with("s]"));
+    }
+
+    #[test]
+    fn test_format_timestamp_off_is_none() {
+        let start = std::time::Instant::now();
+        assert!(super::StdErrLog::format_timestamp(super::Timestamp::Off, start).is_none());
+    }
+
+    #[test]
+    fn test_target_pipe_writes_to_sink() {
+        extern crate log;
+
+        let buf = Arc::new(Mutex::new(Vec::new()));
+        let mut logger = super::new();
+        logger
+            .timestamp(super::Timestamp::Off)
+            .target(super::Target::Pipe(Box::new(SharedBuf(buf.clone()))));
+
+        let record = log::Record::builder()
+            .args(format_args!("hello from a pipe"))
+            .level(log::Level::Error)
+            .module_path(Some(module_path!()))
+            .build();
+        log::Log::log(&logger, &record);
+
+        let output = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("hello from a pipe"));
+    }
+
+    #[test]
+    fn test_log_file_rotates_past_max_bytes() {
+        extern crate log;
+
+        let dir = std::env::temp_dir();
+        let path = dir.join("stderrlog_test_log_file_rotates.log");
+        let rotated = dir.join("stderrlog_test_log_file_rotates.log.1");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&rotated);
+
+        let mut logger = super::new();
+        logger.timestamp(super::Timestamp::Off);
+        logger.log_file(&path, 10).unwrap();
+
+        for _ in 0..5 {
+            let record = log::Record::builder()
+                .args(format_args!("0123456789"))
+                .level(log::Level::Error)
+                .module_path(Some(module_path!()))
+                .build();
+            log::Log::log(&logger, &record);
+        }
+        log::Log::flush(&logger);
+
+        assert!(rotated.exists());
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&rotated);
+    }
+
+    #[test]
+    fn test_parse_filters_per_module() {
+        let mut log = super::new();
+        log.parse_filters("warn,my_crate::db=trace,hyper=off");
+        assert_eq!(LevelFilter::Warn, log.level_for("my_crate"));
+        assert_eq!(LevelFilter::Trace, log.level_for("my_crate::db"));
+        assert_eq!(LevelFilter::Trace, log.level_for("my_crate::db::pool"));
+        assert_eq!(LevelFilter::Off, log.level_for("hyper"));
+    }
+
+    #[test]
+    fn test_parse_filters_does_not_match_sibling_modules() {
+        let mut log = super::new();
+        log.parse_filters("warn,my_crate::db=trace,hyper=off");
+        assert_eq!(LevelFilter::Warn, log.level_for("my_crate::dbx"));
+        assert_eq!(LevelFilter::Warn, log.level_for("hyperfoo"));
+    }
+
+    #[test]
+    fn test_init_from_env_parses_directives() {
+        let var = "STDERRLOG_TEST_INIT_FROM_ENV";
+        std::env::set_var(var, "warn,my_crate::db=trace");
+
+        let mut log = super::new();
+        log.init_from_env(var);
+
+        std::env::remove_var(var);
+
+        assert_eq!(LevelFilter::Warn, log.level_for("anything"));
+        assert_eq!(LevelFilter::Trace, log.level_for("my_crate::db"));
+    }
 }